@@ -55,43 +55,241 @@
 //! For better efficiency, instead of invoking `highlight` or `highlight_with_theme` in a hot
 //! loop consider creating a PulldownHighlighter object once and use it many times.
 //!
+//! ## CSS-class-based output
+//!
+//! By default code is highlighted with inline `style="..."` attributes, baking the theme's
+//! colors into the generated HTML. Calling [`PulldownHighlighter::with_class_style`] switches to
+//! `<span class="...">` output instead, so a static site can ship a single stylesheet (generated
+//! with [`PulldownHighlighter::css`] or the free [`theme_css`] function) and swap themes without
+//! re-highlighting any documents.
+//!
+//! ## Loading extra syntaxes and themes
+//!
+//! [`DefinitionLoader`] merges `.sublime-syntax` and `.tmTheme` files from user-supplied
+//! directories into syntect's bundled defaults ([`DefinitionLoader::with_defaults`]) or into an
+//! otherwise empty set ([`DefinitionLoader::new`]), for languages and themes not covered out of
+//! the box. It cannot start from a `SyntaxSet` or `ThemeSet` supplied by the caller:
+//!
+//! ```no_run
+//! use highlight_pulldown::DefinitionLoader;
+//!
+//! let (syntax_set, theme_set) = DefinitionLoader::with_defaults()
+//!     .add_syntax_folder("extra-syntaxes")
+//!     .unwrap()
+//!     .add_theme_folder("extra-themes")
+//!     .unwrap()
+//!     .build();
+//! ```
+//!
+//! ## Selecting a theme by name
+//!
+//! [`PulldownHighlighter::with_theme_name`] picks a theme out of a [`syntect::highlighting::ThemeSet`]
+//! by name, returning [`Error::InvalidTheme`] rather than requiring callers to juggle a `&Theme`
+//! borrow:
+//!
+//! ```rust
+//! use highlight_pulldown::PulldownHighlighter;
+//! use syntect::highlighting::ThemeSet;
+//! use syntect::parsing::SyntaxSet;
+//!
+//! let theme_set = ThemeSet::load_defaults();
+//! let highlighter = PulldownHighlighter::with_theme_name(
+//!     SyntaxSet::load_defaults_newlines(),
+//!     &theme_set,
+//!     "base16-ocean.dark",
+//! )
+//! .unwrap();
+//! ```
+//!
 //! ## Contributing
 //!
 //! If you happen to use this package, any feedback is more than welcome.
 //!
 //! Contributions in the form of issues or patches via the GitLab repo are even more appreciated.
 
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::path::Path;
+
 use pulldown_cmark::{CodeBlockKind, CowStr, Event, Tag};
-use syntect::highlighting::Theme;
-use syntect::html::highlighted_html_for_string;
-use syntect::parsing::SyntaxSet;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Theme, ThemeSet};
+use syntect::html::{
+    css_for_theme_with_class_style, highlighted_html_for_string, start_highlighted_html_snippet,
+    styled_line_to_highlighted_html, ClassedHTMLGenerator, IncludeBackground,
+};
+use syntect::parsing::{SyntaxReference, SyntaxSet, SyntaxSetBuilder};
+use syntect::util::LinesWithEndings;
 use thiserror::Error;
 
+pub use syntect::html::ClassStyle;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("theme '{0}' is not available")]
     InvalidTheme(String),
     #[error("error highlighting code")]
     HighlightError(#[from] syntect::Error),
+    #[error("error loading syntax or theme definitions")]
+    LoadingError(#[from] syntect::LoadingError),
+    #[error("error loading theme dump: {0}")]
+    DumpError(String),
+    #[error("highlight_iter does not support with_class_style; use highlight() instead")]
+    ClassStyleUnsupported,
+}
+
+/// Builds a [`SyntaxSet`] and [`ThemeSet`] augmented with extra syntax and theme definitions
+/// loaded from directories on disk, so languages and themes not bundled with syntect can be used
+/// without forking this crate.
+#[derive(Default)]
+pub struct DefinitionLoader {
+    syntax_set_builder: SyntaxSetBuilder,
+    theme_set: ThemeSet,
+}
+
+impl DefinitionLoader {
+    /// Start from an empty [`SyntaxSet`] and [`ThemeSet`], seeded only with the "Plain Text"
+    /// syntax every [`SyntaxSet`] is expected to carry (it's what `find_syntax_plain_text`, used
+    /// internally by [`highlight`] and friends, falls back on). A folder added via
+    /// [`DefinitionLoader::add_syntax_folder`] can still define its own `.sublime-syntax` for
+    /// plain text and override it.
+    pub fn new() -> Self {
+        let mut syntax_set_builder = SyntaxSetBuilder::new();
+        syntax_set_builder.add_plain_text_syntax();
+        Self {
+            syntax_set_builder,
+            theme_set: ThemeSet::new(),
+        }
+    }
+
+    /// Start from syntect's bundled syntaxes and themes.
+    pub fn with_defaults() -> Self {
+        Self {
+            syntax_set_builder: SyntaxSet::load_defaults_newlines().into_builder(),
+            theme_set: ThemeSet::load_defaults(),
+        }
+    }
+
+    /// Load every `.sublime-syntax` file found (recursively) under `folder` and merge it in.
+    pub fn add_syntax_folder(mut self, folder: impl AsRef<Path>) -> Result<Self, Error> {
+        self.syntax_set_builder.add_from_folder(folder, true)?;
+        Ok(self)
+    }
+
+    /// Load every `.tmTheme` file found (recursively) under `folder` and merge it in.
+    pub fn add_theme_folder(mut self, folder: impl AsRef<Path>) -> Result<Self, Error> {
+        self.theme_set.add_from_folder(folder)?;
+        Ok(self)
+    }
+
+    /// Finish building, returning the merged [`SyntaxSet`] and [`ThemeSet`].
+    pub fn build(self) -> (SyntaxSet, ThemeSet) {
+        (self.syntax_set_builder.build(), self.theme_set)
+    }
 }
 
 pub struct PulldownHighlighter<'a> {
     syntaxset: SyntaxSet,
-    theme: &'a Theme,
+    theme: Cow<'a, Theme>,
+    class_style: Option<ClassStyle>,
 }
 
 /// A highlighter that can be instantiated once and used many times for better performance.
 impl<'a> PulldownHighlighter<'a> {
-    pub fn new(syntaxset: SyntaxSet, theme: &'a Theme) -> PulldownHighlighter {
-        Self { syntaxset, theme }
+    pub fn new(syntaxset: SyntaxSet, theme: &'a Theme) -> PulldownHighlighter<'a> {
+        Self {
+            syntaxset,
+            theme: Cow::Borrowed(theme),
+            class_style: None,
+        }
+    }
+
+    /// Build a highlighter from a theme looked up by name in `theme_set`, returning
+    /// [`Error::InvalidTheme`] if it isn't present. Unlike [`PulldownHighlighter::new`], the
+    /// theme is cloned out of `theme_set` and owned by the returned highlighter, so callers don't
+    /// need to keep a borrow of the theme (or the theme set) alive.
+    pub fn with_theme_name(
+        syntaxset: SyntaxSet,
+        theme_set: &ThemeSet,
+        name: &str,
+    ) -> Result<PulldownHighlighter<'static>, Error> {
+        let theme = theme_set
+            .themes
+            .get(name)
+            .cloned()
+            .ok_or_else(|| Error::InvalidTheme(name.to_string()))?;
+        Ok(PulldownHighlighter {
+            syntaxset,
+            theme: Cow::Owned(theme),
+            class_style: None,
+        })
+    }
+
+    /// Switch this highlighter to CSS-class-based output (`<span class="...">`) instead of the
+    /// default inline `style="..."` output, so the theme can be swapped at render time via the
+    /// stylesheet returned by [`PulldownHighlighter::css`].
+    ///
+    /// Only affects [`PulldownHighlighter::highlight`]; [`PulldownHighlighter::highlight_iter`]
+    /// doesn't support class-based output and returns [`Error::ClassStyleUnsupported`] once this
+    /// is set.
+    pub fn with_class_style(mut self, class_style: ClassStyle) -> Self {
+        self.class_style = Some(class_style);
+        self
     }
 
     pub fn highlight<'b, It>(&self, events: It) -> Result<Vec<Event<'b>>, Error>
     where
         It: Iterator<Item = Event<'b>>,
     {
-        highlight(&self.syntaxset, &self.theme, events)
+        match self.class_style {
+            Some(class_style) => highlight_classed(&self.syntaxset, class_style, events),
+            None => highlight(&self.syntaxset, self.theme.as_ref(), events),
+        }
+    }
+
+    /// CSS for this highlighter's theme, matching the class names produced when
+    /// [`PulldownHighlighter::with_class_style`] is enabled.
+    pub fn css(&self, class_style: ClassStyle) -> Result<String, Error> {
+        theme_css(self.theme.as_ref(), class_style)
     }
+
+    /// Like [`PulldownHighlighter::highlight`], but lazily highlights and yields events on
+    /// demand instead of buffering the whole document ahead of time.
+    ///
+    /// Returns [`Error::ClassStyleUnsupported`] if [`PulldownHighlighter::with_class_style`] was
+    /// used to build this highlighter, since the streaming adapter only produces inline-styled
+    /// output.
+    pub fn highlight_iter<'b, It>(&'a self, events: It) -> Result<HighlightIter<'a, It>, Error>
+    where
+        It: Iterator<Item = Event<'b>>,
+    {
+        if self.class_style.is_some() {
+            return Err(Error::ClassStyleUnsupported);
+        }
+        Ok(highlight_iter(&self.syntaxset, self.theme.as_ref(), events))
+    }
+}
+
+/// Resolve a fenced code block's info string to a syntax, trying in order: an exact language
+/// token match (which itself already matches syntax names case-insensitively) and a
+/// file-extension match (stripping a leading dot). Extra attributes after the language (`
+/// ```rust,ignore `, ` ```sh title=foo `) are ignored by only looking at the first
+/// whitespace/comma-separated segment. Returns `None` if none of that resolves to a known syntax.
+fn find_syntax_for_info_string<'a>(
+    syntax_set: &'a SyntaxSet,
+    info: &str,
+) -> Option<&'a SyntaxReference> {
+    let token = info
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .next()
+        .unwrap_or("");
+    if token.is_empty() {
+        return None;
+    }
+
+    syntax_set
+        .find_syntax_by_token(token)
+        .or_else(|| syntax_set.find_syntax_by_extension(token.trim_start_matches('.')))
 }
 
 /// Apply syntax highlighting to pulldown-cmark events using this instance's theme.
@@ -120,7 +318,7 @@ where
             Event::Start(Tag::CodeBlock(kind)) => {
                 match kind {
                     CodeBlockKind::Fenced(lang) => {
-                        syntax = syntax_set.find_syntax_by_token(&lang).unwrap_or(syntax)
+                        syntax = find_syntax_for_info_string(syntax_set, &lang).unwrap_or(syntax)
                     }
                     CodeBlockKind::Indented => {}
                 }
@@ -152,6 +350,210 @@ where
     Ok(out_events)
 }
 
+/// Like [`highlight`], but emits `<span class="...">` markup instead of inline `style="..."`
+/// attributes, so the same highlighted HTML can be re-themed by swapping the stylesheet returned
+/// by [`theme_css`].
+fn highlight_classed<'b, It>(
+    syntax_set: &SyntaxSet,
+    class_style: ClassStyle,
+    events: It,
+) -> Result<Vec<Event<'b>>, Error>
+where
+    It: Iterator<Item = Event<'b>>,
+{
+    let mut in_code_block = false;
+
+    let mut syntax = syntax_set.find_syntax_plain_text();
+    let mut lang_label = String::new();
+
+    let mut to_highlight = String::new();
+    let mut out_events = Vec::new();
+
+    for event in events {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                lang_label = match kind {
+                    CodeBlockKind::Fenced(lang) => {
+                        syntax = find_syntax_for_info_string(syntax_set, &lang).unwrap_or(syntax);
+                        lang.split([' ', ',']).next().unwrap_or("").to_string()
+                    }
+                    CodeBlockKind::Indented => String::new(),
+                };
+                in_code_block = true;
+            }
+            Event::End(Tag::CodeBlock(_)) => {
+                if !in_code_block {
+                    panic!("this should never happen");
+                }
+
+                let mut generator =
+                    ClassedHTMLGenerator::new_with_class_style(syntax, syntax_set, class_style);
+                for line in LinesWithEndings::from(&to_highlight) {
+                    generator.parse_html_for_line_which_includes_newline(line)?;
+                }
+                let code = generator.finalize();
+                let label = if lang_label.is_empty() {
+                    syntax.name.to_lowercase()
+                } else {
+                    lang_label.to_lowercase()
+                };
+                let html = format!("<pre><code class=\"language-{label}\">{code}</code></pre>");
+
+                to_highlight.clear();
+                in_code_block = false;
+                out_events.push(Event::Html(CowStr::from(html)));
+            }
+            Event::Text(t) => {
+                if in_code_block {
+                    to_highlight.push_str(&t);
+                } else {
+                    out_events.push(Event::Text(t));
+                }
+            }
+            e => {
+                out_events.push(e);
+            }
+        }
+    }
+
+    Ok(out_events)
+}
+
+/// Return the CSS needed to render output produced with [`PulldownHighlighter::with_class_style`]
+/// for `theme`, so a static site can ship one stylesheet and re-theme highlighted code without
+/// re-highlighting it.
+pub fn theme_css(theme: &Theme, class_style: ClassStyle) -> Result<String, Error> {
+    Ok(css_for_theme_with_class_style(theme, class_style)?)
+}
+
+/// Load a [`ThemeSet`] from a binary dump produced by `syntect::dumps::dump_to_file`, so an app
+/// can bundle one precompiled `.themedump` instead of parsing `.tmTheme` files at startup.
+///
+/// # Panics
+///
+/// Panics if `data` isn't a valid dump, since `syntect::dumps::from_binary` offers no fallible
+/// variant. Only use this with a dump produced by a trusted build step; for a dump loaded from an
+/// untrusted or user-supplied path, prefer [`theme_set_from_dump_file`], which surfaces loading
+/// failures as [`Error::DumpError`].
+pub fn theme_set_from_binary(data: &[u8]) -> ThemeSet {
+    syntect::dumps::from_binary(data)
+}
+
+/// Load a [`ThemeSet`] from a binary dump file on disk, as produced by
+/// `syntect::dumps::dump_to_file`.
+pub fn theme_set_from_dump_file(path: impl AsRef<Path>) -> Result<ThemeSet, Error> {
+    syntect::dumps::from_dump_file(path).map_err(|e| Error::DumpError(e.to_string()))
+}
+
+/// Names of the themes available in `theme_set`, for presenting a choice of themes to users or
+/// validating a config-provided name before calling [`PulldownHighlighter::with_theme_name`].
+pub fn theme_names(theme_set: &ThemeSet) -> impl Iterator<Item = &str> {
+    theme_set.themes.keys().map(String::as_str)
+}
+
+/// Apply syntax highlighting to pulldown-cmark events lazily, one event at a time.
+///
+/// Unlike [`highlight`], this does not collect the whole event stream into a `Vec` before
+/// returning: it wraps `events` in an adapter that pulls and highlights one source event at a
+/// time, so the result can be fed straight into [`pulldown_cmark::html::push_html`]. When a code
+/// block's `Event::Text` is pulled, all of its lines are highlighted at once and the resulting
+/// per-line events are queued until they have been yielded.
+pub fn highlight_iter<'a, 'b, It>(
+    syntax_set: &'a SyntaxSet,
+    theme: &'a Theme,
+    events: It,
+) -> HighlightIter<'a, It>
+where
+    It: Iterator<Item = Event<'b>>,
+{
+    HighlightIter {
+        events,
+        syntax_set,
+        theme,
+        syntax: syntax_set.find_syntax_plain_text(),
+        highlighter: None,
+        pending: VecDeque::new(),
+    }
+}
+
+/// Iterator adapter returned by [`highlight_iter`] and [`PulldownHighlighter::highlight_iter`].
+///
+/// Wraps a source iterator of pulldown-cmark events, forwarding non-code-block events unchanged
+/// and turning each line of a code block's text into a single highlighted `Event::Html`. Since a
+/// code block's text arrives as one `Event::Text` covering every line, a single input event can
+/// expand into several output events; the extra ones are buffered in `pending` and drained before
+/// the source iterator is pulled again.
+///
+/// Since `Iterator::next` can't return a `Result`, a line that syntect fails to highlight is
+/// passed through as a plain `Event::Text` instead of propagating an error (unlike [`highlight`],
+/// which surfaces such failures as `Err`).
+pub struct HighlightIter<'a, It> {
+    events: It,
+    syntax_set: &'a SyntaxSet,
+    theme: &'a Theme,
+    syntax: &'a SyntaxReference,
+    highlighter: Option<HighlightLines<'a>>,
+    pending: VecDeque<Event<'static>>,
+}
+
+impl<'a, 'b, It> Iterator for HighlightIter<'a, It>
+where
+    It: Iterator<Item = Event<'b>>,
+{
+    type Item = Event<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(event) = self.pending.pop_front() {
+            return Some(event);
+        }
+
+        match self.events.next()? {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                if let CodeBlockKind::Fenced(lang) = &kind {
+                    self.syntax =
+                        find_syntax_for_info_string(self.syntax_set, lang).unwrap_or(self.syntax);
+                }
+                self.highlighter = Some(HighlightLines::new(self.syntax, self.theme));
+                let (pre, _background) = start_highlighted_html_snippet(self.theme);
+                Some(Event::Html(CowStr::from(pre)))
+            }
+            Event::End(Tag::CodeBlock(_)) => {
+                if self.highlighter.take().is_none() {
+                    panic!("this should never happen");
+                }
+                Some(Event::Html(CowStr::from("</pre>\n")))
+            }
+            Event::Text(t) => {
+                if let Some(highlighter) = &mut self.highlighter {
+                    // `HighlightLines::highlight_line` carries parser state across calls and must
+                    // be called once per line, but a code block's whole body arrives as a single
+                    // `Event::Text`; split it back into lines before highlighting each one.
+                    for line in LinesWithEndings::from(&t) {
+                        // Unlike `highlight`, this adapter can't surface a `Result`
+                        // (`Iterator::next` isn't fallible), so a line syntect fails to highlight
+                        // is passed through as plain text instead of aborting the whole stream.
+                        let html = highlighter.highlight_line(line, self.syntax_set).ok().and_then(
+                            |regions| {
+                                styled_line_to_highlighted_html(&regions, IncludeBackground::No)
+                                    .ok()
+                            },
+                        );
+                        let event = match html {
+                            Some(html) => Event::Html(CowStr::from(html)),
+                            None => Event::Text(CowStr::from(line.to_string())),
+                        };
+                        self.pending.push_back(event);
+                    }
+                    self.pending.pop_front().or_else(|| self.next())
+                } else {
+                    Some(Event::Text(t))
+                }
+            }
+            e => Some(e),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use syntect::highlighting::ThemeSet;
@@ -189,4 +591,194 @@ mod tests {
 "#;
         assert_eq!(html, expected);
     }
+
+    #[test]
+    fn highlight_iter_matches_highlight() {
+        let markdown = "```python\nprint(\"foo\", 42)\nx = 1\ny = 2\n```";
+
+        let theme_set = ThemeSet::load_defaults();
+        let theme = theme_set.themes.get("base16-ocean.dark").unwrap();
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+
+        let events = pulldown_cmark::Parser::new(markdown);
+        let highlighter = PulldownHighlighter::new(syntax_set, theme);
+        let expected_events = highlighter.highlight(events).unwrap();
+        let mut expected_html = String::new();
+        pulldown_cmark::html::push_html(&mut expected_html, expected_events.into_iter());
+
+        let events = pulldown_cmark::Parser::new(markdown);
+        let mut html = String::new();
+        pulldown_cmark::html::push_html(&mut html, highlighter.highlight_iter(events).unwrap());
+
+        assert_eq!(html, expected_html);
+    }
+
+    #[test]
+    fn highlight_with_class_style() {
+        let markdown = "```rust\nfn main() {}\n```";
+
+        let theme_set = ThemeSet::load_defaults();
+        let theme = theme_set.themes.get("base16-ocean.dark").unwrap();
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let highlighter =
+            PulldownHighlighter::new(syntax_set, theme).with_class_style(ClassStyle::Spaced);
+
+        let events = pulldown_cmark::Parser::new(markdown);
+        let events = highlighter.highlight(events).unwrap();
+
+        let mut html = String::new();
+        pulldown_cmark::html::push_html(&mut html, events.into_iter());
+
+        assert!(html.starts_with("<pre><code class=\"language-rust\">"));
+        assert!(html.ends_with("</code></pre>"));
+        assert!(html.contains("<span class=\""));
+        assert!(!html.contains("style=\""));
+    }
+
+    #[test]
+    fn highlight_iter_rejects_class_style() {
+        let markdown = "```rust\nfn main() {}\n```";
+
+        let theme_set = ThemeSet::load_defaults();
+        let theme = theme_set.themes.get("base16-ocean.dark").unwrap();
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let highlighter =
+            PulldownHighlighter::new(syntax_set, theme).with_class_style(ClassStyle::Spaced);
+
+        let events = pulldown_cmark::Parser::new(markdown);
+        let result = highlighter.highlight_iter(events);
+
+        assert!(matches!(result, Err(Error::ClassStyleUnsupported)));
+    }
+
+    #[test]
+    fn css_for_class_style() {
+        let theme_set = ThemeSet::load_defaults();
+        let theme = theme_set.themes.get("base16-ocean.dark").unwrap();
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let highlighter = PulldownHighlighter::new(syntax_set, &theme);
+
+        let css = highlighter.css(ClassStyle::Spaced).unwrap();
+
+        assert!(!css.is_empty());
+    }
+
+    #[test]
+    fn definition_loader_with_defaults() {
+        let (syntax_set, theme_set) = DefinitionLoader::with_defaults().build();
+
+        assert!(syntax_set.find_syntax_by_token("rust").is_some());
+        assert!(theme_set.themes.contains_key("base16-ocean.dark"));
+    }
+
+    #[test]
+    fn definition_loader_missing_folder_errors() {
+        let result = DefinitionLoader::with_defaults().add_syntax_folder("does-not-exist");
+
+        assert!(matches!(result, Err(Error::LoadingError(_))));
+    }
+
+    #[test]
+    fn definition_loader_new_has_plain_text_fallback() {
+        let (syntax_set, theme_set) = DefinitionLoader::new().build();
+
+        let theme = ThemeSet::load_defaults();
+        let theme = theme.themes.get("base16-ocean.dark").unwrap();
+        let highlighter = PulldownHighlighter::new(syntax_set, theme);
+
+        let events = pulldown_cmark::Parser::new("```\nhello\n```");
+        assert!(highlighter.highlight(events).is_ok());
+
+        assert!(theme_set.themes.is_empty());
+    }
+
+    #[test]
+    fn resolves_fence_language_case_insensitively() {
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+
+        let by_token = find_syntax_for_info_string(&syntax_set, "rust").unwrap();
+        let by_case = find_syntax_for_info_string(&syntax_set, "Rust").unwrap();
+        assert_eq!(by_token.name, by_case.name);
+    }
+
+    #[test]
+    fn resolves_fence_language_by_extension() {
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+
+        let syntax = find_syntax_for_info_string(&syntax_set, ".rs").unwrap();
+        assert_eq!(syntax.name, "Rust");
+    }
+
+    #[test]
+    fn resolves_fence_language_ignoring_trailing_attributes() {
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+
+        let syntax = find_syntax_for_info_string(&syntax_set, "rust,ignore").unwrap();
+        assert_eq!(syntax.name, "Rust");
+    }
+
+    #[test]
+    fn highlighter_with_theme_name() {
+        let theme_set = ThemeSet::load_defaults();
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+
+        let highlighter = PulldownHighlighter::with_theme_name(
+            syntax_set,
+            &theme_set,
+            "base16-ocean.dark",
+        )
+        .unwrap();
+
+        let css = highlighter.css(ClassStyle::Spaced).unwrap();
+        assert!(!css.is_empty());
+    }
+
+    #[test]
+    fn highlighter_with_unknown_theme_name_errors() {
+        let theme_set = ThemeSet::load_defaults();
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+
+        let result =
+            PulldownHighlighter::with_theme_name(syntax_set, &theme_set, "does-not-exist");
+
+        assert!(matches!(result, Err(Error::InvalidTheme(name)) if name == "does-not-exist"));
+    }
+
+    #[test]
+    fn lists_theme_names() {
+        let theme_set = ThemeSet::load_defaults();
+
+        let names: Vec<_> = theme_names(&theme_set).collect();
+
+        assert!(names.contains(&"base16-ocean.dark"));
+    }
+
+    #[test]
+    fn theme_set_from_binary_round_trips() {
+        let theme_set = ThemeSet::load_defaults();
+        let data = syntect::dumps::dump_binary(&theme_set);
+
+        let loaded = theme_set_from_binary(&data);
+
+        assert!(loaded.themes.contains_key("base16-ocean.dark"));
+    }
+
+    #[test]
+    fn theme_set_from_dump_file_round_trips() {
+        let theme_set = ThemeSet::load_defaults();
+        let path = std::env::temp_dir().join("highlight-pulldown-dump-round-trip.themedump");
+        syntect::dumps::dump_to_file(&theme_set, &path).unwrap();
+
+        let loaded = theme_set_from_dump_file(&path).unwrap();
+
+        std::fs::remove_file(&path).ok();
+        assert!(loaded.themes.contains_key("base16-ocean.dark"));
+    }
+
+    #[test]
+    fn theme_set_from_dump_file_missing_path_errors() {
+        let result = theme_set_from_dump_file("does-not-exist.themedump");
+
+        assert!(matches!(result, Err(Error::DumpError(_))));
+    }
 }